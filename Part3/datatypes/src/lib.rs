@@ -0,0 +1,556 @@
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(dead_code, unused_variables, unused_assignments)]
+
+//! Examples covering "Data Types" from *The Rust Programming Language*,
+//! refactored to live inside a `lib.rs` so they can compile and be tested.
+//! Each section mirrors the book's examples with light adaptations for a library crate.
+//!
+//! You can run `cargo test` to execute the included tests and see behavior.
+//!
+//! NOTE:
+//! - The "type annotations needed" compile error from the book is shown here
+//!   as a commented example, because keeping it live would break compilation.
+//! - The "invalid array element access" example is provided in two ways:
+//!   (1) a safe function that returns `Option<T>` instead of panicking, and
+//!   (2) a direct indexing function used in a `#[should_panic]` test to demonstrate the runtime panic.
+//!
+//! This file groups examples into small functions so you can jump around or reuse them.
+//!
+//! ## `no_std` support
+//!
+//! The crate builds with `default-features = false` for bare-metal targets.
+//! Three configurations are supported:
+//! - `std` (default): the floating-point examples use the `std` math methods.
+//! - `libm` without `std`: the same examples delegate to the `libm` free
+//!   functions via the [`FloatMath`] shim trait.
+//! - neither: only the core arithmetic that needs no runtime is available;
+//!   the transcendental examples ([`hypotenuse_example`]) are compiled out.
+
+// ---------------------------------------------------------
+// 1) Type annotations and `parse`
+// ---------------------------------------------------------
+
+/// Demonstrates adding a type annotation so `.parse()` knows what to produce.
+pub fn parse_guess_with_type_annotation() -> u32 {
+    let guess: u32 = "42".parse().expect("Not a number!");
+    guess
+}
+
+// This is the version that would fail to compile without a type annotation.
+// Leaving it as a comment so the crate compiles:
+//
+// ```compile_fail
+// let guess = "42".parse().expect("Not a number!");
+// ```
+
+// ---------------------------------------------------------
+// 2) Integer types, literals, defaults
+// ---------------------------------------------------------
+
+/// Showcases declaration of various integer types and literals.
+pub fn integer_types_and_literals() {
+    // Defaults: integer literals default to i32 unless otherwise specified.
+    let default_int = 123; // i32 by default
+
+    // Explicit sizes and signedness
+    let a: i8 = -5;
+    let b: u8 = 250;
+    let c: i16 = -1234;
+    let d: u16 = 65535;
+    let e: i32 = -12_345_678;
+    let f: u32 = 12_345_678;
+    let g: i64 = -9_223_372_036_854_775_808_i64 + 1;
+    let h: u64 = 18_446_744_073_709_551_615_u64;
+    let i: i128 = -170_141_183_460_469_231_731_687_303_715_884_105_728_i128;
+    let j: u128 = 340_282_366_920_938_463_463_374_607_431_768_211_455_u128;
+
+    // Arch-dependent sizes
+    let k: isize = -1;
+    let l: usize = 42;
+
+    // Integer literals in various bases
+    let dec = 98_222;
+    let hex = 0xff;
+    let oct = 0o77;
+    let bin = 0b1111_0000;
+    let byte: u8 = b'A';
+
+    // Use variables to avoid "unused variable" warnings in doc builds
+    let _ = (default_int, a, b, c, d, e, f, g, h, i, j, k, l, dec, hex, oct, bin, byte);
+}
+
+/// Returns the size in bits of isize / usize on this target.
+pub fn arch_pointer_width_bits() -> (usize, usize) {
+    (core::mem::size_of::<isize>() * 8, core::mem::size_of::<usize>() * 8)
+}
+
+// ---------------------------------------------------------
+// 2.5) Bounded trait and generic min/max example
+// ---------------------------------------------------------
+
+/// Exposes the min/max bounds of a numeric type generically, mirroring the
+/// `num-traits` `Bounded` trait. Implemented for every primitive numeric type
+/// plus `core::num::Wrapping<T>`, so wrapping arithmetic has well-defined
+/// bounds too. This turns the static literal table in
+/// [`integer_types_and_literals`] into something testable generically.
+pub trait Bounded {
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Bounded for $t {
+                fn min_value() -> Self { <$t>::MIN }
+                fn max_value() -> Self { <$t>::MAX }
+            }
+        )*
+    };
+}
+
+impl_bounded!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64,
+);
+
+impl<T: Bounded> Bounded for core::num::Wrapping<T> {
+    fn min_value() -> Self {
+        core::num::Wrapping(T::min_value())
+    }
+
+    fn max_value() -> Self {
+        core::num::Wrapping(T::max_value())
+    }
+}
+
+/// Returns the full `(min, max)` bound pair for any `T: Bounded`.
+pub fn bounds_example<T: Bounded>() -> (T, T) {
+    (T::min_value(), T::max_value())
+}
+
+// ---------------------------------------------------------
+// 3) Integer overflow helpers
+// ---------------------------------------------------------
+
+/// A uniform, overflow-aware arithmetic interface implemented for every
+/// primitive integer type, mirroring the `CheckedAdd`/`WrappingAdd`/
+/// `SaturatingAdd`/`OverflowingAdd` split from the `num-traits` ecosystem.
+/// Each method simply forwards to the matching inherent method, so callers
+/// who only know `T: OverflowOps` get overflow-aware math without ever
+/// matching on the concrete type.
+pub trait OverflowOps: Sized + Copy {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_overflow_ops {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl OverflowOps for $t {
+                fn wrapping_add(self, rhs: Self) -> Self { <$t>::wrapping_add(self, rhs) }
+                fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+                fn overflowing_add(self, rhs: Self) -> (Self, bool) { <$t>::overflowing_add(self, rhs) }
+                fn saturating_add(self, rhs: Self) -> Self { <$t>::saturating_add(self, rhs) }
+
+                fn wrapping_sub(self, rhs: Self) -> Self { <$t>::wrapping_sub(self, rhs) }
+                fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+                fn overflowing_sub(self, rhs: Self) -> (Self, bool) { <$t>::overflowing_sub(self, rhs) }
+                fn saturating_sub(self, rhs: Self) -> Self { <$t>::saturating_sub(self, rhs) }
+
+                fn wrapping_mul(self, rhs: Self) -> Self { <$t>::wrapping_mul(self, rhs) }
+                fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+                fn overflowing_mul(self, rhs: Self) -> (Self, bool) { <$t>::overflowing_mul(self, rhs) }
+                fn saturating_mul(self, rhs: Self) -> Self { <$t>::saturating_mul(self, rhs) }
+            }
+        )*
+    };
+}
+
+impl_overflow_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Demonstrates standard library helpers for dealing with overflow, generic
+/// over any `T: OverflowOps` so the demonstration runs over any integer width.
+pub fn overflow_helpers_example<T: OverflowOps>(x: T, y: T) -> (T, Option<T>, (T, bool), T) {
+    // wrapping_* (wraps in all modes)
+    let wrapping = x.wrapping_add(y);
+
+    // checked_* (returns None on overflow)
+    let checked = x.checked_add(y);
+
+    // overflowing_* (returns value + did_overflow flag)
+    let overflowing = x.overflowing_add(y);
+
+    // saturating_* (saturates at min/max)
+    let saturating = x.saturating_add(y);
+
+    (wrapping, checked, overflowing, saturating)
+}
+
+// ---------------------------------------------------------
+// 4) Floating-point types and numeric ops
+// ---------------------------------------------------------
+
+/// Returns an f64 (default) and an explicitly typed f32.
+pub fn floating_point_examples() -> (f64, f32) {
+    let x = 2.0;     // f64
+    let y: f32 = 3.0; // f32
+    (x, y)
+}
+
+/// Basic numeric operations across integers and floats.
+pub fn numeric_operations() -> (i32, f64, i32, f64, i32) {
+    // addition
+    let sum = 5 + 10;
+
+    // subtraction
+    let difference = 95.5 - 4.3;
+
+    // multiplication
+    let product = 4 * 30;
+
+    // division
+    let quotient = 56.7 / 32.2;
+    let truncated = -5 / 3; // results in -1
+
+    // remainder
+    let remainder = 43 % 5;
+
+    // return several representatives
+    (sum, difference, product, quotient, truncated)
+}
+
+// ---------------------------------------------------------
+// 4.5) `no_std`-friendly float math shim
+// ---------------------------------------------------------
+
+/// Selects the floating-point math backend at compile time, so the
+/// transcendental examples compile identically under `std` and under
+/// `no_std` + `libm`.
+pub trait FloatMath: Sized {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn abs(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl FloatMath for f32 {
+    fn sqrt(self) -> Self { f32::sqrt(self) }
+    fn sin(self) -> Self { f32::sin(self) }
+    fn powf(self, n: Self) -> Self { f32::powf(self, n) }
+    fn abs(self) -> Self { f32::abs(self) }
+    fn hypot(self, other: Self) -> Self { f32::hypot(self, other) }
+}
+
+#[cfg(feature = "std")]
+impl FloatMath for f64 {
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+    fn sin(self) -> Self { f64::sin(self) }
+    fn powf(self, n: Self) -> Self { f64::powf(self, n) }
+    fn abs(self) -> Self { f64::abs(self) }
+    fn hypot(self, other: Self) -> Self { f64::hypot(self, other) }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl FloatMath for f32 {
+    fn sqrt(self) -> Self { libm::sqrtf(self) }
+    fn sin(self) -> Self { libm::sinf(self) }
+    fn powf(self, n: Self) -> Self { libm::powf(self, n) }
+    fn abs(self) -> Self { libm::fabsf(self) }
+    fn hypot(self, other: Self) -> Self { libm::hypotf(self, other) }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl FloatMath for f64 {
+    fn sqrt(self) -> Self { libm::sqrt(self) }
+    fn sin(self) -> Self { libm::sin(self) }
+    fn powf(self, n: Self) -> Self { libm::pow(self, n) }
+    fn abs(self) -> Self { libm::fabs(self) }
+    fn hypot(self, other: Self) -> Self { libm::hypot(self, other) }
+}
+
+/// Computes the hypotenuse of a right triangle with legs `a` and `b`.
+/// Works identically whether the active backend is `std` or `libm`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn hypotenuse_example<T: FloatMath>(a: T, b: T) -> T {
+    a.hypot(b)
+}
+
+// ---------------------------------------------------------
+// 5) Booleans
+// ---------------------------------------------------------
+
+pub fn boolean_examples() -> (bool, bool) {
+    let t = true;
+    let f: bool = false;
+    (t, f)
+}
+
+// ---------------------------------------------------------
+// 6) Characters
+// ---------------------------------------------------------
+
+pub fn char_examples() -> (char, char, char) {
+    let c = 'z';
+    let z: char = 'ℤ'; // with explicit annotation
+    let heart_eyed_cat = '😻';
+    (c, z, heart_eyed_cat)
+}
+
+// ---------------------------------------------------------
+// 7) Tuples
+// ---------------------------------------------------------
+
+pub fn tuple_make() -> (i32, f64, u8) {
+    let tup: (i32, f64, u8) = (500, 6.4, 1);
+    tup
+}
+
+pub fn tuple_destructure_y() -> f64 {
+    let tup = (500, 6.4, 1);
+    let (x, y, z) = tup;
+    y
+}
+
+pub fn tuple_indexing() -> (i32, f64, u8) {
+    let x: (i32, f64, u8) = (500, 6.4, 1);
+    let five_hundred = x.0;
+    let six_point_four = x.1;
+    let one = x.2;
+    (five_hundred, six_point_four, one)
+}
+
+/// Demonstrates unit type `()` return.
+pub fn unit_example() {
+    // Implicitly returns () (unit)
+}
+
+// ---------------------------------------------------------
+// 8) Arrays
+// ---------------------------------------------------------
+
+pub fn array_make_and_access() -> (i32, i32) {
+    let a = [1, 2, 3, 4, 5];
+    let first = a[0];
+    let second = a[1];
+    (first, second)
+}
+
+pub fn array_type_annotation() -> [i32; 5] {
+    let a: [i32; 5] = [1, 2, 3, 4, 5];
+    a
+}
+
+pub fn array_repeated_init() -> [i32; 5] {
+    [3; 5]
+}
+
+pub fn months_array() -> [&'static str; 12] {
+    [
+        "January", "February", "March", "April", "May", "June",
+        "July", "August", "September", "October", "November", "December"
+    ]
+}
+
+/// Safe access: returns `Some(value)` if index is in-bounds, else `None`.
+pub fn array_get_safe(a: &[i32; 5], index: usize) -> Option<i32> {
+    a.get(index).copied()
+}
+
+/// Direct indexing (may panic if out-of-bounds). Used by the `#[should_panic]` test below.
+pub fn array_index_unsafe(a: &[i32; 5], index: usize) -> i32 {
+    a[index]
+}
+
+// ---------------------------------------------------------
+// 9) Tests mirroring book behavior
+// ---------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_guess() {
+        assert_eq!(parse_guess_with_type_annotation(), 42u32);
+    }
+
+    #[test]
+    fn test_pointer_widths() {
+        let (isize_bits, usize_bits) = arch_pointer_width_bits();
+        assert!(isize_bits == 32 || isize_bits == 64);
+        assert!(usize_bits == 32 || usize_bits == 64);
+    }
+
+    #[test]
+    fn test_overflow_helpers() {
+        // choose values that overflow u8 when added
+        let (wrap, checked, (overflowed_val, did_overflow), sat) = overflow_helpers_example(250, 10);
+        assert_eq!(wrap, 4);               // 260 -> wrap to 4
+        assert_eq!(checked, None);         // overflow -> None
+        assert!(did_overflow);             // overflow happened
+        assert_eq!(sat, u8::MAX);          // saturates at 255
+        let _ = overflowed_val;            // not asserting exact value here
+    }
+
+    #[test]
+    fn test_bounded_and_wrapping() {
+        assert_eq!(bounds_example::<u8>(), (0u8, 255u8));
+        assert_eq!(bounds_example::<i8>(), (-128i8, 127i8));
+        assert_eq!(bounds_example::<u128>(), (0u128, u128::MAX));
+
+        // Wrapping<T> bounds match T's bounds, and wrapping arithmetic
+        // around the boundary is well-defined: MAX + 1 wraps to MIN.
+        let max = core::num::Wrapping::<u8>::max_value();
+        let min = core::num::Wrapping::<u8>::min_value();
+        assert_eq!(max.0, u8::MAX);
+        assert_eq!(min.0, u8::MIN);
+        assert_eq!((max + core::num::Wrapping(1u8)).0, min.0);
+    }
+
+    /// Asserts `T::max_value().wrapping_add(one)` wraps around to
+    /// `T::min_value()`. Takes `one` explicitly since this crate has no
+    /// `One` trait to conjure it from `T` generically.
+    fn assert_wraps_from_max_to_min<T>(one: T)
+    where
+        T: Bounded + OverflowOps + PartialEq + core::fmt::Debug,
+    {
+        assert_eq!(T::max_value().wrapping_add(one), T::min_value());
+    }
+
+    #[test]
+    fn test_bounded_wraps_across_every_integer_width() {
+        assert_wraps_from_max_to_min(1i8);
+        assert_wraps_from_max_to_min(1i16);
+        assert_wraps_from_max_to_min(1i32);
+        assert_wraps_from_max_to_min(1i64);
+        assert_wraps_from_max_to_min(1i128);
+        assert_wraps_from_max_to_min(1isize);
+        assert_wraps_from_max_to_min(1u8);
+        assert_wraps_from_max_to_min(1u16);
+        assert_wraps_from_max_to_min(1u32);
+        assert_wraps_from_max_to_min(1u64);
+        assert_wraps_from_max_to_min(1u128);
+        assert_wraps_from_max_to_min(1usize);
+    }
+
+    #[test]
+    fn test_overflow_ops_generic_across_widths() {
+        // u8 (existing narrow-width behavior, now routed through the generic trait)
+        let (wrap, checked, (_, did_overflow), sat) = overflow_helpers_example(250u8, 10u8);
+        assert_eq!(wrap, 4);
+        assert_eq!(checked, None);
+        assert!(did_overflow);
+        assert_eq!(sat, u8::MAX);
+
+        // i32, no overflow
+        let (wrap, checked, (val, did_overflow), sat) = overflow_helpers_example(5i32, 10i32);
+        assert_eq!(wrap, 15);
+        assert_eq!(checked, Some(15));
+        assert_eq!(val, 15);
+        assert!(!did_overflow);
+        assert_eq!(sat, 15);
+
+        // u128, overflowing at the top of the range
+        let (wrap, checked, (_, did_overflow), sat) = overflow_helpers_example(u128::MAX, 1u128);
+        assert_eq!(wrap, 0);
+        assert_eq!(checked, None);
+        assert!(did_overflow);
+        assert_eq!(sat, u128::MAX);
+    }
+
+    #[test]
+    fn test_floats_and_ops() {
+        let (x, y) = floating_point_examples();
+        assert_eq!(x, 2.0);
+        assert_eq!(y, 3.0f32);
+
+        let (sum, difference, product, quotient, truncated) = numeric_operations();
+        assert_eq!(sum, 15);
+        assert!((difference - 91.2).abs() < 1e-10);
+        assert_eq!(product, 120);
+        assert!((quotient - (56.7 / 32.2)).abs() < 1e-10);
+        assert_eq!(truncated, -1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hypotenuse_std_backend() {
+        let h = hypotenuse_example(3.0f64, 4.0f64);
+        assert!((h - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_booleans_and_chars() {
+        assert_eq!(boolean_examples(), (true, false));
+        let (c, z, heart) = char_examples();
+        assert_eq!(c, 'z');
+        assert_eq!(z, 'ℤ');
+        assert_eq!(heart, '😻');
+    }
+
+    #[test]
+    fn test_tuples() {
+        assert_eq!(tuple_make(), (500, 6.4, 1));
+        assert_eq!(tuple_destructure_y(), 6.4);
+        assert_eq!(tuple_indexing(), (500, 6.4, 1));
+    }
+
+    #[test]
+    fn test_arrays() {
+        assert_eq!(array_make_and_access(), (1, 2));
+        assert_eq!(array_type_annotation(), [1, 2, 3, 4, 5]);
+        assert_eq!(array_repeated_init(), [3, 3, 3, 3, 3]);
+
+        let months = months_array();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], "January");
+    }
+
+    #[test]
+    fn test_array_safe_and_unsafe_access() {
+        let a = [1, 2, 3, 4, 5];
+        assert_eq!(array_get_safe(&a, 0), Some(1));
+        assert_eq!(array_get_safe(&a, 9), None);
+        assert_eq!(array_index_unsafe(&a, 1), 2);
+    }
+
+    /// Demonstrates the runtime panic when indexing out of bounds,
+    /// mirroring the book's "Invalid Array Element Access" behavior.
+    #[test]
+    #[should_panic]
+    fn test_array_index_out_of_bounds_panics() {
+        let a = [1, 2, 3, 4, 5];
+        // Intentionally index past the end to trigger a panic:
+        let _ = array_index_unsafe(&a, 10);
+    }
+}
+
+/// Exercises the `libm` backend independently of `std`. Run with
+/// `cargo test --no-default-features --features libm` to select this path.
+#[cfg(all(test, feature = "libm", not(feature = "std")))]
+mod libm_tests {
+    use super::*;
+
+    #[test]
+    fn test_hypotenuse_libm_backend() {
+        let h = hypotenuse_example(3.0f64, 4.0f64);
+        assert!((h - 5.0).abs() < 1e-9);
+    }
+}